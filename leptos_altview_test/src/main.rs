@@ -1,10 +1,54 @@
+use leptos::*;
 use leptos_altview::view;
 
+#[component]
+fn Counter(initial: i32, children: Children) -> impl IntoView {
+    view![span(class = "counter")(initial, children())]
+}
+
+fn highlight(element: HtmlElement<html::AnyElement>, color: &'static str) {
+    _ = element.style("background-color", color);
+}
+
 fn main() {
+    let input_ref = create_node_ref::<html::Input>();
+    let (count, _set_count) = create_signal(0);
+    let href = "/";
+    let extra_attrs = vec![("data-kind", "demo"), ("aria-live", "polite")];
+
+    let _layout = view![(header()("top"), main()("content"), footer()("bottom"))];
+
     view![div(
+        id = "root",
         class = "hi",
-        foo = "bar",
-        name = "ari",
-        style = "width: 100%" // on = move |_| {}
-    )("hello", "there")];
+        "foo" = "bar",
+        data-name = "ari",
+        aria-label = "greeting",
+        style = "width: 100%",
+        on:click = move |_| {}
+    )(
+        "hello",
+        "there",
+        a(href)("home"),
+        p(..extra_attrs)("spread"),
+        mark(use:highlight = "yellow")("directive"),
+        br(),
+        article(inner_html = "<em>rendered</em>").on_mount(|_| {}),
+        span(class = ["name", ("active", move || count.get() > 0)], style:font-weight = "bold")("world"),
+        input(ref = input_ref, readonly, prop:value = move || count.get().to_string()),
+        sl-badge(variant = "primary", class:is-hidden = move || count.get() == 0)("new"),
+        Counter(initial = 3)("clicks"),
+        if count.get() > 0 {
+            span()("positive")
+        } else {
+            "zero"
+        },
+        match count.get() {
+            0 => "none",
+            n => span()(n),
+        },
+        ("left", span()("right")),
+        svg(viewBox = "0 0 10 10")(a(href = "#")(circle(cx = 5, cy = 5, r = 4))),
+        ul()(for n in vec![1, 2, 3] key = *n { li()(n) })
+    )];
 }
@@ -0,0 +1,330 @@
+use crate::{elements::HTML_ELEMENTS, events::EVENTS};
+
+/// Attributes which are enabled by their presence alone, such as `disabled`.
+pub const BOOLEAN_ATTRIBUTES: &[&str] = &[
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "inert",
+    "ismap",
+    "itemscope",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+];
+
+/// Attributes which can be used on any html element.
+pub const GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accesskey",
+    "autocapitalize",
+    "autofocus",
+    "class",
+    "contenteditable",
+    "dir",
+    "draggable",
+    "enterkeyhint",
+    "exportparts",
+    "hidden",
+    "id",
+    "inert",
+    "inputmode",
+    "is",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "lang",
+    "nonce",
+    "part",
+    "popover",
+    "role",
+    "slot",
+    "spellcheck",
+    "style",
+    "tabindex",
+    "title",
+    "translate",
+];
+
+/// Attribute prefixes which are valid on any html element, such as `data-id`.
+pub const GLOBAL_ATTRIBUTE_PREFIXES: &[&str] = &["aria-", "data-"];
+
+/// Whether `name` is an attribute of the element `tag`, custom elements only know the global
+/// attributes.
+pub fn is_attribute(tag: &str, name: &str) -> bool {
+    let element_attributes = if HTML_ELEMENTS.contains(&tag) {
+        element_attributes(tag).unwrap_or_default()
+    } else if tag.contains('-') {
+        &[]
+    } else {
+        return false;
+    };
+
+    GLOBAL_ATTRIBUTES.contains(&name)
+        || element_attributes.contains(&name)
+        || GLOBAL_ATTRIBUTE_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        || is_event_handler(name)
+}
+
+/// Whether `name` is an inline event handler such as `onclick`.
+fn is_event_handler(name: &str) -> bool {
+    name.strip_prefix("on")
+        .is_some_and(|event| EVENTS.contains(&event))
+}
+
+/// Attributes specific to an html element, on top of the global attributes.
+///
+/// Returns `None` for elements which aren't validated, such as the `svg` and `math` roots.
+pub fn element_attributes(tag: &str) -> Option<&'static [&'static str]> {
+    let attributes: &[&str] = match tag {
+        "a" => &[
+            "download",
+            "href",
+            "hreflang",
+            "ping",
+            "referrerpolicy",
+            "rel",
+            "target",
+            "type",
+        ],
+        "area" => &[
+            "alt",
+            "coords",
+            "download",
+            "href",
+            "ping",
+            "referrerpolicy",
+            "rel",
+            "shape",
+            "target",
+        ],
+        "audio" => &[
+            "autoplay",
+            "controls",
+            "crossorigin",
+            "loop",
+            "muted",
+            "preload",
+            "src",
+        ],
+        "base" => &["href", "target"],
+        "blockquote" | "q" => &["cite"],
+        "button" => &[
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "name",
+            "popovertarget",
+            "popovertargetaction",
+            "type",
+            "value",
+        ],
+        "canvas" => &["height", "width"],
+        "col" | "colgroup" => &["span"],
+        "data" => &["value"],
+        "del" | "ins" => &["cite", "datetime"],
+        "details" => &["name", "open"],
+        "dialog" => &["open"],
+        "embed" => &["height", "src", "type", "width"],
+        "fieldset" => &["disabled", "form", "name"],
+        "form" => &[
+            "accept-charset",
+            "action",
+            "autocomplete",
+            "enctype",
+            "method",
+            "name",
+            "novalidate",
+            "rel",
+            "target",
+        ],
+        "html" => &["xmlns"],
+        "iframe" => &[
+            "allow",
+            "allowfullscreen",
+            "height",
+            "loading",
+            "name",
+            "referrerpolicy",
+            "sandbox",
+            "src",
+            "srcdoc",
+            "width",
+        ],
+        "img" => &[
+            "alt",
+            "crossorigin",
+            "decoding",
+            "fetchpriority",
+            "height",
+            "ismap",
+            "loading",
+            "referrerpolicy",
+            "sizes",
+            "src",
+            "srcset",
+            "usemap",
+            "width",
+        ],
+        "input" => &[
+            "accept",
+            "alt",
+            "autocomplete",
+            "capture",
+            "checked",
+            "dirname",
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "height",
+            "list",
+            "max",
+            "maxlength",
+            "min",
+            "minlength",
+            "multiple",
+            "name",
+            "pattern",
+            "placeholder",
+            "popovertarget",
+            "popovertargetaction",
+            "readonly",
+            "required",
+            "size",
+            "src",
+            "step",
+            "type",
+            "value",
+            "width",
+        ],
+        "label" => &["for"],
+        "li" => &["value"],
+        "link" => &[
+            "as",
+            "blocking",
+            "crossorigin",
+            "disabled",
+            "fetchpriority",
+            "href",
+            "hreflang",
+            "imagesizes",
+            "imagesrcset",
+            "integrity",
+            "media",
+            "referrerpolicy",
+            "rel",
+            "sizes",
+            "type",
+        ],
+        "map" => &["name"],
+        "meta" => &[
+            "charset",
+            "content",
+            "http-equiv",
+            "media",
+            "name",
+            "property",
+        ],
+        "meter" => &["high", "low", "max", "min", "optimum", "value"],
+        "object" => &["data", "form", "height", "name", "type", "width"],
+        "ol" => &["reversed", "start", "type"],
+        "optgroup" => &["disabled", "label"],
+        "option" => &["disabled", "label", "selected", "value"],
+        "output" => &["for", "form", "name"],
+        "param" => &["name", "value"],
+        "portal" => &["referrerpolicy", "src"],
+        "progress" => &["max", "value"],
+        "script" => &[
+            "async",
+            "blocking",
+            "crossorigin",
+            "defer",
+            "fetchpriority",
+            "integrity",
+            "nomodule",
+            "referrerpolicy",
+            "src",
+            "type",
+        ],
+        "select" => &[
+            "autocomplete",
+            "disabled",
+            "form",
+            "multiple",
+            "name",
+            "required",
+            "size",
+        ],
+        "slot" => &["name"],
+        "source" => &["height", "media", "sizes", "src", "srcset", "type", "width"],
+        "style" => &["blocking", "media"],
+        "td" => &["colspan", "headers", "rowspan"],
+        "template" => &[
+            "shadowrootclonable",
+            "shadowrootdelegatesfocus",
+            "shadowrootmode",
+        ],
+        "textarea" => &[
+            "autocomplete",
+            "cols",
+            "dirname",
+            "disabled",
+            "form",
+            "maxlength",
+            "minlength",
+            "name",
+            "placeholder",
+            "readonly",
+            "required",
+            "rows",
+            "wrap",
+        ],
+        "th" => &["abbr", "colspan", "headers", "rowspan", "scope"],
+        "time" => &["datetime"],
+        "track" => &["default", "kind", "label", "src", "srclang"],
+        "video" => &[
+            "autoplay",
+            "controls",
+            "crossorigin",
+            "height",
+            "loop",
+            "muted",
+            "playsinline",
+            "poster",
+            "preload",
+            "src",
+            "width",
+        ],
+        "math" | "svg" => return None,
+        _ => &[],
+    };
+
+    Some(attributes)
+}
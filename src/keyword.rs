@@ -1,4 +1,11 @@
 use syn::custom_keyword;
 
 custom_keyword!(class);
+custom_keyword!(fragment);
+custom_keyword!(id);
+custom_keyword!(inner_html);
+custom_keyword!(key);
+custom_keyword!(on);
+custom_keyword!(prop);
+custom_keyword!(_ref);
 custom_keyword!(style);
@@ -1,13 +1,17 @@
+mod attributes;
+mod elements;
+mod events;
 mod keyword;
 mod node;
+mod suggest;
 
-use node::Node;
+use node::Root;
 use proc_macro::TokenStream;
 use quote::ToTokens;
 use syn::parse_macro_input;
 
 #[proc_macro]
 pub fn view(tokens: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(tokens as Node);
+    let input = parse_macro_input!(tokens as Root);
     input.into_token_stream().into()
 }
@@ -1,20 +1,67 @@
-use quote::{quote, ToTokens};
+use proc_macro2::Span;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
+    braced, bracketed,
+    ext::IdentExt,
     parenthesized,
     parse::{discouraged::Speculative, Parse, ParseStream},
+    parse_quote,
     punctuated::Punctuated,
-    token, Expr, ExprTuple, Ident, Token,
+    token, AngleBracketedGenericArguments, Expr, ExprLit, ExprTuple, Ident, Lit, LitFloat, LitInt,
+    LitStr, Pat, Token,
 };
 
-use crate::keyword;
+use crate::{
+    attributes::{element_attributes, is_attribute, BOOLEAN_ATTRIBUTES, GLOBAL_ATTRIBUTES},
+    elements::{HTML_ELEMENTS, MATH_ELEMENTS, SVG_ELEMENTS, VOID_ELEMENTS},
+    events::EVENTS,
+    keyword,
+    suggest::did_you_mean,
+};
+
+/// The top level of a `view!`, either a single node or a fragment of siblings.
+#[derive(Clone, Debug)]
+pub enum Root {
+    Fragment(Fragment),
+    Node(Node),
+}
+
+impl Parse for Root {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut root = if Fragment::peek(input) {
+            Root::Fragment(input.parse()?)
+        } else {
+            Root::Node(input.parse()?)
+        };
+
+        match &mut root {
+            Root::Fragment(fragment) => fragment.children.set_namespace(Namespace::Html)?,
+            Root::Node(node) => node.set_namespace(Namespace::Html)?,
+        }
+
+        Ok(root)
+    }
+}
+
+impl ToTokens for Root {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Root::Fragment(fragment) => fragment.to_tokens(tokens),
+            Root::Node(node) => node.to_tokens(tokens),
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct Node {
-    pub tag: Ident,
+    pub tag: Name,
+    #[allow(dead_code)]
     pub fields_paren_token: Option<token::Paren>,
     pub fields: Punctuated<Field, Token![,]>,
     pub children_paren_token: Option<token::Paren>,
     pub children: Children,
+    pub methods: Vec<MethodCall>,
+    pub namespace: Namespace,
 }
 
 impl Parse for Node {
@@ -34,20 +81,40 @@ impl Parse for Node {
             let content;
             let paren_token = parenthesized!(content in input);
             let fork = content.fork();
-            match fork.parse_terminated(Field::parse, Token![,]) {
-                Ok(new_attrs) => {
+            let attrs_result = fork.parse_terminated(Field::parse, Token![,]);
+
+            // The first of two groups can only be fields, so its errors are reported as is
+            let attrs_result = if input.peek(token::Paren) {
+                Ok(attrs_result?)
+            } else {
+                attrs_result
+            };
+
+            match attrs_result {
+                // A lone group of bare names such as `p(name)` is children, names are only punned
+                // when children follow such as `a(href)("home")`, or on void elements which can't
+                // have children such as `img(src)`
+                Ok(new_attrs)
+                    if input.peek(token::Paren)
+                        || Node::is_void(&tag)
+                        || !Field::is_ambiguous(&new_attrs) =>
+                {
+                    Field::validate_all(&new_attrs)?;
                     fields_paren_token = Some(paren_token);
                     fields = new_attrs;
                     content.advance_to(&fork);
                 }
-                Err(attrs_err) => {
+                attrs_result => {
                     // Attrs failed, lets try children
                     parsed_children = true;
+                    children_paren_token = Some(paren_token);
 
-                    children = input.parse().map_err(|children_err| {
+                    children = content.parse().map_err(|children_err| {
                         let mut err =
                             syn::Error::new(content.span(), "expected attributes or children");
-                        err.combine(attrs_err);
+                        if let Err(attrs_err) = attrs_result {
+                            err.combine(attrs_err);
+                        }
                         err.combine(children_err);
                         err
                     })?;
@@ -62,13 +129,217 @@ impl Parse for Node {
             children = content.parse()?;
         }
 
-        Ok(Node {
+        let mut methods = Vec::new();
+        while input.peek(Token![.]) && !input.peek(Token![..]) {
+            methods.push(input.parse()?);
+        }
+
+        let node = Node {
             tag,
             fields_paren_token,
             fields,
             children_paren_token,
             children,
-        })
+            methods,
+            namespace: Namespace::Html,
+        };
+
+        if let Some(paren_token) = node.children_paren_token {
+            if Node::is_void(&node.tag) {
+                return Err(syn::Error::new(
+                    paren_token.span.join(),
+                    format!(
+                        "`{}` is a void element and cannot have children",
+                        node.tag.to_lit_str().value()
+                    ),
+                ));
+            }
+        }
+
+        if node.is_component() {
+            for field in &node.fields {
+                field.validate_prop()?;
+            }
+        }
+
+        if !node.children.is_empty() {
+            let inner_html = node.fields.iter().find_map(|field| match field {
+                Field::InnerHtml(inner_html) => Some(inner_html),
+                _ => None,
+            });
+            if let Some(inner_html) = inner_html {
+                return Err(syn::Error::new(
+                    inner_html.name.span,
+                    "`inner_html` would overwrite the children of this node",
+                ));
+            }
+        }
+
+        Ok(node)
+    }
+}
+
+impl Node {
+    /// Void elements such as `br` cannot have children, so a lone paren group is always fields.
+    fn is_void(tag: &Name) -> bool {
+        match tag {
+            Name::Ident(ident) => VOID_ELEMENTS.contains(&ident.unraw().to_string().as_str()),
+            Name::Custom(_) | Name::Literal(_) => false,
+        }
+    }
+
+    /// Checks element tags are known in their namespace, components and custom elements are
+    /// left to the compiler.
+    fn validate_tag(&self) -> syn::Result<()> {
+        let Name::Ident(ident) = &self.tag else {
+            return Ok(());
+        };
+        if self.is_component() {
+            return Ok(());
+        }
+
+        let name = ident.unraw().to_string();
+        let elements = self.namespace.elements();
+        if elements.contains(&name.as_str()) {
+            return Ok(());
+        }
+
+        let other_namespace = [Namespace::Html, Namespace::Svg, Namespace::Math]
+            .into_iter()
+            .find(|namespace| namespace.elements().contains(&name.as_str()));
+        let message = match (other_namespace, did_you_mean(&name, elements)) {
+            (Some(Namespace::Html), _) if self.namespace == Namespace::Svg => {
+                format!("`{name}` is an html element, place it inside `foreignObject`")
+            }
+            (Some(Namespace::Html), _) => {
+                format!("`{name}` is an html element, place it outside of `math`")
+            }
+            (Some(Namespace::Svg), _) => {
+                format!("`{name}` is an svg element, place it inside `svg`")
+            }
+            (Some(Namespace::Math), _) => {
+                format!("`{name}` is a math element, place it inside `math`")
+            }
+            (None, Some(suggestion)) => {
+                format!("unknown element `{name}`, did you mean `{suggestion}`?")
+            }
+            (None, None) => format!(
+                "unknown element `{name}`, custom elements must contain a hyphen such as `{}`",
+                custom_element_name(&name)
+            ),
+        };
+        Err(syn::Error::new(ident.span(), message))
+    }
+
+    /// Checks the attributes of an html element against the attributes it supports.
+    fn validate_attrs(&self) -> syn::Result<()> {
+        let Name::Ident(ident) = &self.tag else {
+            return Ok(());
+        };
+        let tag = ident.unraw().to_string();
+        if !HTML_ELEMENTS.contains(&tag.as_str()) {
+            return Ok(());
+        }
+        let Some(element_attributes) = element_attributes(&tag) else {
+            return Ok(());
+        };
+
+        let attributes = [GLOBAL_ATTRIBUTES, element_attributes].concat();
+        for field in &self.fields {
+            let Field::Attr(attr) = field else {
+                continue;
+            };
+            let name = match &attr.name {
+                Name::Ident(ident) => ident.unraw().to_string(),
+                Name::Custom(name) => name.value(),
+                Name::Literal(_) => continue,
+            };
+            if is_attribute(&tag, &name) {
+                continue;
+            }
+
+            let message = match did_you_mean(&name, &attributes) {
+                Some(suggestion) => {
+                    format!("unknown attribute `{name}` on `{tag}`, did you mean `{suggestion}`?")
+                }
+                None => format!(
+                    "unknown attribute `{name}` on `{tag}`, write it as a string literal such as `\"{name}\"` to set it anyway"
+                ),
+            };
+            return Err(syn::Error::new(attr.name.to_lit_str().span(), message));
+        }
+
+        Ok(())
+    }
+
+    /// Sets the namespace of this node, and the namespace of its descendants based on its tag.
+    ///
+    /// Tags and attributes are validated here, as they depend on the namespace of the node.
+    pub fn set_namespace(&mut self, namespace: Namespace) -> syn::Result<()> {
+        self.namespace = namespace;
+        self.validate_tag()?;
+        if namespace == Namespace::Html {
+            self.validate_attrs()?;
+        }
+
+        let children_namespace = match &self.tag {
+            Name::Ident(ident) if ident == "svg" => Namespace::Svg,
+            Name::Ident(ident) if ident == "math" => Namespace::Math,
+            Name::Ident(ident) if ident == "foreignObject" && namespace == Namespace::Svg => {
+                Namespace::Html
+            }
+            _ => namespace,
+        };
+        self.children.set_namespace(children_namespace)
+    }
+
+    /// PascalCase tags are components, everything else is an html element.
+    pub fn is_component(&self) -> bool {
+        match &self.tag {
+            Name::Ident(ident) => ident
+                .to_string()
+                .starts_with(|c: char| c.is_ascii_uppercase()),
+            Name::Custom(_) | Name::Literal(_) => false,
+        }
+    }
+
+    fn component_to_tokens(&self, tag: &Ident, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            fields, children, ..
+        } = self;
+
+        let props = fields.iter().filter_map(Field::to_prop_tokens);
+        let children = if children.is_empty() {
+            quote! {}
+        } else {
+            let fragment = children.to_fragment_tokens();
+            quote! {
+                .children(Box::new(move || #fragment))
+            }
+        };
+        // Point missing prop errors from the props builder at the tag
+        let build = quote_spanned! {tag.span()=>
+            .build()
+        };
+
+        tokens.extend(quote! {
+            leptos::component_view(
+                &#tag,
+                leptos::component_props_builder(&#tag)
+                    #(#props)*
+                    #children
+                    #build
+            )
+        });
+
+        for field in fields {
+            if let Field::Event(event) = field {
+                event.to_tokens(tokens);
+            }
+        }
+        for method in &self.methods {
+            method.to_tokens(tokens);
+        }
     }
 }
 
@@ -81,14 +352,245 @@ impl ToTokens for Node {
             ..
         } = self;
 
-        tokens.extend(quote! {
-            leptos::html::#tag()
+        if let Name::Ident(ident) = tag {
+            if self.is_component() {
+                self.component_to_tokens(ident, tokens);
+                return;
+            }
+        }
+
+        // A whole style string replaces the style attribute, so it's set before any properties
+        let (styles, fields): (Vec<_>, Vec<_>) = fields.iter().partition(|field| {
+            matches!(
+                field,
+                Field::Style(Style {
+                    value: StyleValue::Static(_),
+                    ..
+                })
+            )
         });
 
-        for field in fields {
-            field.to_tokens(tokens);
+        let mut element = self.namespace.element_tokens(tag);
+        for field in styles.into_iter().chain(fields) {
+            field.apply(&mut element);
+        }
+        children.to_tokens(&mut element);
+        for method in &self.methods {
+            method.to_tokens(&mut element);
+        }
+
+        tokens.extend(element);
+    }
+}
+
+/// A tag or attribute name.
+///
+/// Names which aren't valid identifiers can be written with hyphens, such as `sl-button` or
+/// `aria-label`, or as a string literal. Literal attribute names skip attribute validation.
+#[derive(Clone, Debug)]
+pub enum Name {
+    Ident(Ident),
+    Custom(LitStr),
+    Literal(LitStr),
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Name::parse_with(input, Ident::parse, false)
+    }
+}
+
+/// Whether `b` directly follows `a` without any whitespace, such as the tokens of `sl-button`.
+fn is_adjacent(a: Span, b: Span) -> bool {
+    let (end, start) = (a.end(), b.start());
+    end.line == start.line && end.column == start.column
+}
+
+impl Name {
+    /// Parses an attribute name, which unlike a tag can be a keyword such as `type` or `for`.
+    pub fn parse_attribute(input: ParseStream) -> syn::Result<Self> {
+        Name::parse_with(input, Ident::parse_any, false)
+    }
+
+    /// Parses a class name, which can also contain decimals and fractions such as `p-2.5` or
+    /// `w-1/2`.
+    pub fn parse_class(input: ParseStream) -> syn::Result<Self> {
+        Name::parse_with(input, Ident::parse_any, true)
+    }
+
+    fn parse_with(
+        input: ParseStream,
+        parse_ident: fn(ParseStream) -> syn::Result<Ident>,
+        is_class: bool,
+    ) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(Name::Literal(input.parse()?));
+        }
+
+        let ident = parse_ident(input)?;
+        let mut name = ident.to_string();
+        let mut end = ident.span();
+
+        // Only separators written without whitespace join segments, so `a - b` stays a
+        // subtraction
+        loop {
+            let fork = input.fork();
+            let (separator, separator_span) = if fork.peek(Token![-]) {
+                ('-', fork.parse::<Token![-]>()?.span)
+            } else if is_class && fork.peek(Token![/]) {
+                ('/', fork.parse::<Token![/]>()?.span)
+            } else {
+                break;
+            };
+            if !is_adjacent(end, separator_span) || !is_adjacent(separator_span, fork.span()) {
+                break;
+            }
+
+            name.push(separator);
+            if fork.peek(LitInt) {
+                let segment: LitInt = fork.parse()?;
+                name.push_str(&segment.to_string());
+                end = segment.span();
+            } else if is_class && fork.peek(LitFloat) {
+                let segment: LitFloat = fork.parse()?;
+                name.push_str(&segment.to_string());
+                end = segment.span();
+            } else {
+                let segment = Ident::parse_any(&fork)?;
+                name.push_str(&segment.to_string());
+                end = segment.span();
+            }
+            input.advance_to(&fork);
+        }
+
+        if ident != name {
+            Ok(Name::Custom(LitStr::new(&name, ident.span())))
+        } else {
+            Ok(Name::Ident(ident))
+        }
+    }
+
+    /// The name as it appears in the DOM.
+    pub fn to_lit_str(&self) -> LitStr {
+        match self {
+            Name::Ident(ident) => LitStr::new(&ident.unraw().to_string(), ident.span()),
+            Name::Custom(name) | Name::Literal(name) => name.clone(),
+        }
+    }
+}
+
+/// A builder method called on a node after its fields and children, such as `.on_mount(f)`.
+#[derive(Clone, Debug)]
+pub struct MethodCall {
+    #[allow(dead_code)]
+    pub dot_token: Token![.],
+    pub method: Ident,
+    pub turbofish: Option<AngleBracketedGenericArguments>,
+    #[allow(dead_code)]
+    pub paren_token: token::Paren,
+    pub args: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for MethodCall {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let dot_token = input.parse()?;
+        let method = input.parse()?;
+        let turbofish = if input.peek(Token![::]) {
+            Some(AngleBracketedGenericArguments::parse_turbofish(input)?)
+        } else {
+            None
+        };
+        let content;
+        let paren_token = parenthesized!(content in input);
+
+        Ok(MethodCall {
+            dot_token,
+            method,
+            turbofish,
+            paren_token,
+            args: content.parse_terminated(Expr::parse, Token![,])?,
+        })
+    }
+}
+
+impl ToTokens for MethodCall {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            method,
+            turbofish,
+            args,
+            ..
+        } = self;
+
+        tokens.extend(quote! {
+            .#method #turbofish (#args)
+        })
+    }
+}
+
+/// Suggests a custom element name for `name`, which needs to be lowercase with a hyphen.
+fn custom_element_name(name: &str) -> String {
+    let mut custom = String::new();
+    for c in name.chars() {
+        if c == '_' {
+            custom.push('-');
+        } else if c.is_ascii_uppercase() {
+            custom.push('-');
+            custom.push(c.to_ascii_lowercase());
+        } else {
+            custom.push(c);
+        }
+    }
+
+    let custom = custom.trim_matches('-');
+    if custom.contains('-') {
+        custom.to_string()
+    } else {
+        format!("my-{custom}")
+    }
+}
+
+/// The module element functions are taken from, leptos splits these to avoid clashes such as
+/// the html and svg `a` elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Namespace {
+    Html,
+    Svg,
+    Math,
+}
+
+impl Namespace {
+    /// The elements leptos exposes in this namespace.
+    fn elements(self) -> &'static [&'static str] {
+        match self {
+            Namespace::Html => HTML_ELEMENTS,
+            Namespace::Svg => SVG_ELEMENTS,
+            Namespace::Math => MATH_ELEMENTS,
+        }
+    }
+
+    fn element_tokens(self, tag: &Name) -> proc_macro2::TokenStream {
+        let module = match self {
+            Namespace::Html => quote! { leptos::html },
+            Namespace::Svg => quote! { leptos::svg },
+            Namespace::Math => quote! { leptos::math },
+        };
+
+        match tag {
+            // Elements named after keywords have a trailing underscore, such as svg `use_`
+            Name::Ident(ident) if ident.to_string().starts_with("r#") => {
+                let ident = format_ident!("{}_", ident.unraw(), span = ident.span());
+                quote! { #module::#ident() }
+            }
+            Name::Ident(ident) => quote! { #module::#ident() },
+            Name::Custom(name) if self == Namespace::Math && name.value() == "annotation-xml" => {
+                let ident = Ident::new("annotation_xml", name.span());
+                quote! { #module::#ident() }
+            }
+            Name::Custom(name) | Name::Literal(name) => quote! {
+                leptos::html::custom(leptos::html::Custom::new(#name))
+            },
         }
-        children.to_tokens(tokens);
     }
 }
 
@@ -96,9 +598,13 @@ impl ToTokens for Node {
 pub enum Field {
     Attr(Attr),
     Class(Class),
-    // Event(Event),
-    // Id(Id),
-    // Ref(Ref),
+    Directive(Directive),
+    Event(Event),
+    Id(Id),
+    InnerHtml(InnerHtml),
+    Prop(Prop),
+    Ref(Ref),
+    Spread(Spread),
     Style(Style),
 }
 
@@ -106,6 +612,20 @@ impl Parse for Field {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if input.peek(keyword::class) {
             Ok(Field::Class(input.parse()?))
+        } else if input.peek(Token![use]) && input.peek2(Token![:]) {
+            Ok(Field::Directive(input.parse()?))
+        } else if input.peek(keyword::on) && input.peek2(Token![:]) {
+            Ok(Field::Event(input.parse()?))
+        } else if input.peek(keyword::id) {
+            Ok(Field::Id(input.parse()?))
+        } else if input.peek(keyword::inner_html) {
+            Ok(Field::InnerHtml(input.parse()?))
+        } else if input.peek(keyword::prop) && input.peek2(Token![:]) {
+            Ok(Field::Prop(input.parse()?))
+        } else if input.peek(Token![ref]) || input.peek(keyword::_ref) {
+            Ok(Field::Ref(input.parse()?))
+        } else if input.peek(Token![..]) {
+            Ok(Field::Spread(input.parse()?))
         } else if input.peek(keyword::style) {
             Ok(Field::Style(input.parse()?))
         } else {
@@ -114,29 +634,199 @@ impl Parse for Field {
     }
 }
 
-impl ToTokens for Field {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+impl Field {
+    /// Whether the fields are only bare names, which could also be children such as `p(name)`.
+    fn is_ambiguous(fields: &Punctuated<Field, Token![,]>) -> bool {
+        !fields.is_empty() && fields.iter().all(Field::is_bare_name)
+    }
+
+    /// Whether the field is a name without a value which could also be a variable, such as
+    /// `href` or `class`.
+    fn is_bare_name(&self) -> bool {
+        match self {
+            Field::Attr(Attr {
+                name: Name::Ident(name),
+                equals_token: None,
+                ..
+            }) => !is_keyword(name),
+            Field::Class(Class {
+                class_name: None,
+                equals_token: None,
+                ..
+            })
+            | Field::Id(Id {
+                equals_token: None, ..
+            })
+            | Field::Style(Style {
+                property: None,
+                equals_token: None,
+                ..
+            }) => true,
+            _ => false,
+        }
+    }
+
+    /// Checks the field can be passed to a component as a prop.
+    fn validate_prop(&self) -> syn::Result<()> {
+        match self {
+            Field::Class(Class {
+                name,
+                value: ClassValue::Dynamic(..) | ClassValue::List(..),
+                ..
+            }) => Err(syn::Error::new(
+                name.span,
+                "dynamic classes and class lists cannot be passed to a component",
+            )),
+            Field::Style(Style {
+                name,
+                value: StyleValue::Dynamic(..),
+                ..
+            }) => Err(syn::Error::new(
+                name.span,
+                "style properties cannot be passed to a component",
+            )),
+            Field::Directive(directive) => Err(syn::Error::new(
+                directive.use_token.span,
+                "directives cannot be used on a component",
+            )),
+            Field::InnerHtml(inner_html) => Err(syn::Error::new(
+                inner_html.name.span,
+                "inner html cannot be set on a component",
+            )),
+            Field::Prop(prop) => Err(syn::Error::new(
+                prop.prop_token.span,
+                "dom properties cannot be set on a component",
+            )),
+            Field::Ref(node_ref) => Err(syn::Error::new(
+                node_ref.ref_token.span,
+                "node refs cannot be attached to a component",
+            )),
+            Field::Spread(spread) => Err(syn::Error::new(
+                spread.dot2_token.spans[0],
+                "attributes cannot be spread onto a component",
+            )),
+            Field::Attr(Attr {
+                name: Name::Custom(name) | Name::Literal(name),
+                ..
+            }) => Err(syn::Error::new(
+                name.span(),
+                format!("`{}` is not a valid prop name", name.value()),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Expands the field as a prop builder call, events are attached to the view instead.
+    fn to_prop_tokens(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Field::Attr(Attr {
+                name: Name::Ident(name),
+                value,
+                ..
+            }) => Some(quote! { .#name(#value) }),
+            Field::Class(Class {
+                name,
+                value: ClassValue::Static(value),
+                ..
+            }) => {
+                let name = Ident::new("class", name.span);
+                Some(quote! { .#name(#value) })
+            }
+            Field::Id(Id { name, value, .. }) => {
+                let name = Ident::new("id", name.span);
+                Some(quote! { .#name(#value) })
+            }
+            Field::Style(Style {
+                name,
+                value: StyleValue::Static(value),
+                ..
+            }) => {
+                let name = Ident::new("style", name.span);
+                Some(quote! { .#name(#value) })
+            }
+            Field::Attr(_)
+            | Field::Class(_)
+            | Field::Directive(_)
+            | Field::Event(_)
+            | Field::InnerHtml(_)
+            | Field::Prop(_)
+            | Field::Ref(_)
+            | Field::Spread(_)
+            | Field::Style(_) => None,
+        }
+    }
+
+    /// Checks constraints which span multiple fields of the same node.
+    fn validate_all(fields: &Punctuated<Field, Token![,]>) -> syn::Result<()> {
+        let mut ids = fields.iter().filter_map(|field| match field {
+            Field::Id(id) => Some(id),
+            _ => None,
+        });
+        if let Some(duplicate) = ids.nth(1) {
+            return Err(syn::Error::new(
+                duplicate.name.span,
+                "duplicate `id` field, a node can only have one id",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Field {
+    /// Applies the field to the element builder expression.
+    ///
+    /// Most fields chain a method onto the element, but spreads need to wrap it.
+    fn apply(&self, element: &mut proc_macro2::TokenStream) {
         match self {
-            Field::Attr(attr) => attr.to_tokens(tokens),
-            Field::Class(class) => class.to_tokens(tokens),
-            Field::Style(style) => style.to_tokens(tokens),
+            Field::Attr(attr) => attr.to_tokens(element),
+            Field::Class(class) => class.to_tokens(element),
+            Field::Directive(directive) => directive.to_tokens(element),
+            Field::Event(event) => event.to_tokens(element),
+            Field::Id(id) => id.to_tokens(element),
+            Field::InnerHtml(inner_html) => inner_html.to_tokens(element),
+            Field::Prop(prop) => prop.to_tokens(element),
+            Field::Ref(node_ref) => node_ref.to_tokens(element),
+            Field::Spread(spread) => *element = spread.wrap(element),
+            Field::Style(style) => style.to_tokens(element),
         }
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct Attr {
-    pub name: Ident,
-    pub equals_token: Token![=],
+    pub name: Name,
+    pub equals_token: Option<Token![=]>,
     pub value: Expr,
 }
 
 impl Parse for Attr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = Name::parse_attribute(input)?;
+
+        let (equals_token, value) = match &name {
+            // Boolean attributes such as `disabled` are enabled by their bare name
+            Name::Ident(ident)
+                if peek_punned(input)
+                    && BOOLEAN_ATTRIBUTES.contains(&ident.unraw().to_string().as_str()) =>
+            {
+                let value = quote_spanned! {ident.span()=> true};
+                (None, parse_quote!(#value))
+            }
+            Name::Ident(ident) if peek_punned(input) && is_keyword(ident) => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("`{ident}` is a keyword, so it needs a value such as `{ident} = ...`"),
+                ));
+            }
+            Name::Ident(ident) => parse_punned(input, ident)?,
+            Name::Custom(_) | Name::Literal(_) => (Some(input.parse()?), input.parse()?),
+        };
+
         Ok(Attr {
-            name: input.parse()?,
-            equals_token: input.parse()?,
-            value: input.parse()?,
+            name,
+            equals_token,
+            value,
         })
     }
 }
@@ -145,7 +835,7 @@ impl ToTokens for Attr {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let Self { name, value, .. } = self;
 
-        let name = name.to_string();
+        let name = name.to_lit_str();
         tokens.extend(quote! {
             .attr(#name, #value)
         });
@@ -154,14 +844,6 @@ impl ToTokens for Attr {
         //     quote! {
         //         .classes(#value)
         //     }
-        // } else if name == "id" {
-        //     quote! {
-        //         .id(#value)
-        //     }
-        // } else if name == "_ref" {
-        //     quote! {
-        //         .node_ref(#value)
-        //     }
         // } else if name == "style" {
         //     quote! {
         //         .style(#value)
@@ -180,23 +862,78 @@ impl ToTokens for Attr {
 #[derive(Clone, Debug)]
 pub struct Class {
     pub name: keyword::class,
-    pub equals_token: Token![=],
+    #[allow(dead_code)]
+    pub class_name: Option<(Token![:], Name)>,
+    #[allow(dead_code)]
+    pub equals_token: Option<Token![=]>,
     pub value: ClassValue,
 }
 
 impl Parse for Class {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: keyword::class = input.parse()?;
+        let class_name: Option<(Token![:], Name)> = if input.peek(Token![:]) {
+            Some((input.parse()?, Name::parse_class(input)?))
+        } else {
+            None
+        };
+        let (equals_token, value) = match &class_name {
+            Some((_, class_name)) => {
+                let class_name = class_name.to_lit_str();
+                let equals_token = input.parse()?;
+                let value =
+                    ClassValue::Dynamic(parse_quote!(#class_name), Box::new(input.parse()?));
+                (Some(equals_token), value)
+            }
+            None => parse_punned(input, &Ident::new("class", name.span))?,
+        };
+
         Ok(Class {
-            name: input.parse()?,
-            equals_token: input.parse()?,
-            value: input.parse()?,
+            name,
+            class_name,
+            equals_token,
+            value,
         })
     }
 }
 
 impl ToTokens for Class {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let expanded = match &self.value {
+        self.value.to_tokens(tokens);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ClassValue {
+    Static(Expr),
+    Dynamic(Box<Expr>, Box<Expr>),
+    List(
+        #[allow(dead_code)] token::Bracket,
+        Punctuated<ClassValue, Token![,]>,
+    ),
+}
+
+impl Parse for ClassValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(token::Bracket) {
+            let content;
+            let bracket_token = bracketed!(content in input);
+            return Ok(ClassValue::List(
+                bracket_token,
+                content.parse_terminated(ClassValue::parse, Token![,])?,
+            ));
+        }
+
+        match parse_pair(input)? {
+            Some((name, class)) => Ok(ClassValue::Dynamic(Box::new(name), Box::new(class))),
+            None => Ok(ClassValue::Static(input.parse()?)),
+        }
+    }
+}
+
+impl ToTokens for ClassValue {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let expanded = match self {
             ClassValue::Static(value) => {
                 quote! {
                     .classes(#value)
@@ -207,55 +944,221 @@ impl ToTokens for Class {
                     .class(#name, #class)
                 }
             }
+            ClassValue::List(_, values) => {
+                let values = values.iter();
+                quote! {
+                    #(#values)*
+                }
+            }
         };
 
         tokens.extend(expanded);
     }
 }
 
-#[derive(Clone, Debug)]
-pub enum ClassValue {
-    Static(Expr),
-    Dynamic(Expr, Expr),
-}
+/// Parses a `(name, value)` tuple, returning `None` if the input isn't a tuple.
+fn parse_pair(input: ParseStream) -> syn::Result<Option<(Expr, Expr)>> {
+    let fork = input.fork();
+    let Ok(tuple) = fork.parse::<ExprTuple>() else {
+        return Ok(None);
+    };
+    input.advance_to(&fork);
 
-impl Parse for ClassValue {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
-        let fork = input.fork();
-        match fork.parse::<ExprTuple>() {
-            Ok(tuple) => {
-                input.advance_to(&fork);
-
-                let tuple_len = tuple.elems.len();
-                let mut tuple_elems = tuple.elems.into_iter();
-                let name = tuple_elems
-                    .next()
-                    .ok_or_else(|| fork.error("expected a tuple with 2 items"))?;
-                let class = tuple_elems
-                    .next()
-                    .ok_or_else(|| fork.error("expected a tuple with 2 items"))?;
-
-                if tuple_elems.next().is_some() {
-                    return Err(fork.error(format!("tuple has {} items, expected 2", tuple_len)));
-                }
+    let tuple_len = tuple.elems.len();
+    let mut tuple_elems = tuple.elems.into_iter();
+    let name = tuple_elems
+        .next()
+        .ok_or_else(|| fork.error("expected a tuple with 2 items"))?;
+    let value = tuple_elems
+        .next()
+        .ok_or_else(|| fork.error("expected a tuple with 2 items"))?;
 
-                Ok(ClassValue::Dynamic(name, class))
-            }
-            Err(_) => Ok(ClassValue::Static(input.parse()?)),
-        }
+    if tuple_elems.next().is_some() {
+        return Err(fork.error(format!("tuple has {} items, expected 2", tuple_len)));
     }
+
+    Ok(Some((name, value)))
 }
 
-#[derive(Clone, Debug)]
-pub struct Style {
-    pub name: keyword::style,
-    pub equals_token: Token![=],
-    pub value: Expr,
+/// Whether the identifier is a keyword such as `type`, which can't be punned as a variable.
+fn is_keyword(ident: &Ident) -> bool {
+    syn::parse2::<Ident>(ident.to_token_stream()).is_err()
 }
 
-impl Parse for Style {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Style {
+/// Whether a field has no value, such as `href` in `a(href)`.
+fn peek_punned(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(Token![,])
+}
+
+/// Parses `= value`, or uses the variable named after the field when it has no value.
+///
+/// The variable keeps the span of the field, so a missing variable is reported on the field.
+fn parse_punned<T: Parse>(input: ParseStream, name: &Ident) -> syn::Result<(Option<Token![=]>, T)> {
+    if peek_punned(input) {
+        return Ok((None, syn::parse2(name.to_token_stream())?));
+    }
+
+    Ok((Some(input.parse()?), input.parse()?))
+}
+
+#[derive(Clone, Debug)]
+pub struct Directive {
+    pub use_token: Token![use],
+    #[allow(dead_code)]
+    pub colon_token: Token![:],
+    pub handler: Ident,
+    pub value: Option<(Token![=], Expr)>,
+}
+
+impl Parse for Directive {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let use_token = input.parse()?;
+        let colon_token = input.parse()?;
+        let handler = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+
+        Ok(Directive {
+            use_token,
+            colon_token,
+            handler,
+            value,
+        })
+    }
+}
+
+impl ToTokens for Directive {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { handler, value, .. } = self;
+
+        // Directives without a value are passed `()`, such as `use:autofocus`
+        let value = match value {
+            Some((_, value)) => quote! { #value },
+            None => quote! { () },
+        };
+
+        // Directives are called with the element once it's mounted, such as
+        // `fn tooltip(element: HtmlElement<AnyElement>, text: &str)`
+        tokens.extend(quote! {
+            .on_mount({
+                let value = #value;
+                move |element| #handler(element.into_any(), value)
+            })
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Event {
+    #[allow(dead_code)]
+    pub on_token: keyword::on,
+    #[allow(dead_code)]
+    pub colon_token: Token![:],
+    pub name: Ident,
+    #[allow(dead_code)]
+    pub equals_token: Token![=],
+    pub handler: Expr,
+}
+
+impl Parse for Event {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let on_token = input.parse()?;
+        let colon_token = input.parse()?;
+        let name: Ident = input.parse()?;
+        let event = name.to_string();
+        if !EVENTS.contains(&event.as_str()) {
+            let message = match did_you_mean(&event, EVENTS) {
+                Some(suggestion) => {
+                    format!("unknown event `{event}`, did you mean `{suggestion}`?")
+                }
+                None => {
+                    format!("unknown event `{event}`, see `leptos::ev` for the supported events")
+                }
+            };
+            return Err(syn::Error::new(name.span(), message));
+        }
+
+        Ok(Event {
+            on_token,
+            colon_token,
+            name,
+            equals_token: input.parse()?,
+            handler: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Event {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { name, handler, .. } = self;
+
+        tokens.extend(quote! {
+            .on(leptos::ev::#name, #handler)
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Id {
+    pub name: keyword::id,
+    #[allow(dead_code)]
+    pub equals_token: Option<Token![=]>,
+    pub value: Expr,
+}
+
+impl Parse for Id {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: keyword::id = input.parse()?;
+        let (equals_token, value) = parse_punned(input, &Ident::new("id", name.span))?;
+
+        Ok(Id {
+            name,
+            equals_token,
+            value,
+        })
+    }
+}
+
+impl ToTokens for Id {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { value, .. } = self;
+
+        // `.id()` only accepts strings, so other values such as signals go through the reactive
+        // attribute
+        let expanded = if matches!(
+            value,
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(_),
+                ..
+            })
+        ) {
+            quote! {
+                .id(#value)
+            }
+        } else {
+            quote! {
+                .attr("id", #value)
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InnerHtml {
+    pub name: keyword::inner_html,
+    #[allow(dead_code)]
+    pub equals_token: Token![=],
+    pub value: Expr,
+}
+
+impl Parse for InnerHtml {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(InnerHtml {
             name: input.parse()?,
             equals_token: input.parse()?,
             value: input.parse()?,
@@ -263,16 +1166,183 @@ impl Parse for Style {
     }
 }
 
-impl ToTokens for Style {
+impl ToTokens for InnerHtml {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let Self { value, .. } = self;
 
         tokens.extend(quote! {
-            .style(#value)
+            .inner_html(#value)
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Prop {
+    pub prop_token: keyword::prop,
+    #[allow(dead_code)]
+    pub colon_token: Token![:],
+    pub name: Name,
+    #[allow(dead_code)]
+    pub equals_token: Token![=],
+    pub value: Expr,
+}
+
+impl Parse for Prop {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Prop {
+            prop_token: input.parse()?,
+            colon_token: input.parse()?,
+            name: Name::parse_attribute(input)?,
+            equals_token: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Prop {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { name, value, .. } = self;
+
+        let name = name.to_lit_str();
+        tokens.extend(quote! {
+            .prop(#name, #value)
         })
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct Ref {
+    pub ref_token: Token![ref],
+    #[allow(dead_code)]
+    pub equals_token: Token![=],
+    pub value: Expr,
+}
+
+impl Parse for Ref {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ref_token = if input.peek(keyword::_ref) {
+            let keyword: keyword::_ref = input.parse()?;
+            Token![ref](keyword.span)
+        } else {
+            input.parse()?
+        };
+
+        Ok(Ref {
+            ref_token,
+            equals_token: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Ref {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { value, .. } = self;
+
+        tokens.extend(quote! {
+            .node_ref(#value)
+        })
+    }
+}
+
+/// Sets each `(name, value)` pair from a collection as an attribute, written as `..attrs`.
+#[derive(Clone, Debug)]
+pub struct Spread {
+    pub dot2_token: Token![..],
+    pub attrs: Expr,
+}
+
+impl Parse for Spread {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Spread {
+            dot2_token: input.parse()?,
+            attrs: input.parse()?,
+        })
+    }
+}
+
+impl Spread {
+    fn wrap(&self, element: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let Self { attrs, .. } = self;
+
+        quote! {
+            ::core::iter::IntoIterator::into_iter(#attrs)
+                .fold(#element, |element, (name, value)| element.attr(name, value))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Style {
+    pub name: keyword::style,
+    #[allow(dead_code)]
+    pub property: Option<(Token![:], Name)>,
+    #[allow(dead_code)]
+    pub equals_token: Option<Token![=]>,
+    pub value: StyleValue,
+}
+
+impl Parse for Style {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: keyword::style = input.parse()?;
+        let property: Option<(Token![:], Name)> = if input.peek(Token![:]) {
+            Some((input.parse()?, Name::parse_attribute(input)?))
+        } else {
+            None
+        };
+        let (equals_token, value) = match &property {
+            Some((_, property)) => {
+                let property = property.to_lit_str();
+                let equals_token = input.parse()?;
+                let value = StyleValue::Dynamic(parse_quote!(#property), Box::new(input.parse()?));
+                (Some(equals_token), value)
+            }
+            None => parse_punned(input, &Ident::new("style", name.span))?,
+        };
+
+        Ok(Style {
+            name,
+            property,
+            equals_token,
+            value,
+        })
+    }
+}
+
+impl ToTokens for Style {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let expanded = match &self.value {
+            StyleValue::Static(value) => {
+                quote! {
+                    .attr("style", #value)
+                }
+            }
+            StyleValue::Dynamic(name, value) => {
+                quote! {
+                    .style(#name, #value)
+                }
+            }
+        };
+
+        tokens.extend(expanded);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum StyleValue {
+    Static(Box<Expr>),
+    Dynamic(Box<Expr>, Box<Expr>),
+}
+
+impl Parse for StyleValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        match parse_pair(input)? {
+            Some((name, value)) => Ok(StyleValue::Dynamic(Box::new(name), Box::new(value))),
+            None => Ok(StyleValue::Static(Box::new(input.parse()?))),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Children(Punctuated<Child, Token![,]>);
 
@@ -286,6 +1356,39 @@ impl Parse for Children {
     }
 }
 
+impl Children {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn set_namespace(&mut self, namespace: Namespace) -> syn::Result<()> {
+        for child in &mut self.0 {
+            child.set_namespace(namespace)?;
+        }
+        Ok(())
+    }
+
+    /// Expands the children as a `Fragment`, for places which need a single view.
+    pub fn to_fragment_tokens(&self) -> proc_macro2::TokenStream {
+        let views = self.0.iter().map(Child::to_view_tokens);
+
+        quote! {
+            leptos::Fragment::lazy(|| vec![#(#views),*])
+        }
+    }
+
+    /// Expands the children as a single `View`, avoiding a fragment for a lone child.
+    pub fn to_view_tokens(&self) -> proc_macro2::TokenStream {
+        match self.0.first() {
+            Some(child) if self.0.len() == 1 => child.to_view_tokens(),
+            _ => {
+                let fragment = self.to_fragment_tokens();
+                quote! { leptos::IntoView::into_view(#fragment) }
+            }
+        }
+    }
+}
+
 impl ToTokens for Children {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         for child in &self.0 {
@@ -295,23 +1398,778 @@ impl ToTokens for Children {
 }
 
 #[derive(Clone, Debug)]
-pub struct Child {
-    pub expr: Expr,
+pub enum Child {
+    Node(Box<Node>),
+    If(If),
+    For(Box<For>),
+    Match(Match),
+    Fragment(Fragment),
+    Expr(Expr),
+}
+
+impl Child {
+    fn set_namespace(&mut self, namespace: Namespace) -> syn::Result<()> {
+        match self {
+            Child::Node(node) => node.set_namespace(namespace),
+            Child::If(if_) => if_.set_namespace(namespace),
+            Child::For(for_) => for_.children.set_namespace(namespace),
+            Child::Match(match_) => {
+                for arm in &mut match_.arms {
+                    arm.body.set_namespace(namespace)?;
+                }
+                Ok(())
+            }
+            Child::Fragment(fragment) => fragment.children.set_namespace(namespace),
+            Child::Expr(_) => Ok(()),
+        }
+    }
+
+    fn to_view_tokens(&self) -> proc_macro2::TokenStream {
+        match self {
+            Child::Node(node) => quote! { leptos::IntoView::into_view(#node) },
+            Child::If(if_) => quote! { leptos::IntoView::into_view(#if_) },
+            Child::For(for_) => quote! { leptos::IntoView::into_view(#for_) },
+            Child::Match(match_) => quote! { leptos::IntoView::into_view(#match_) },
+            Child::Fragment(fragment) => quote! { leptos::IntoView::into_view(#fragment) },
+            Child::Expr(expr) => quote! { leptos::IntoView::into_view(#expr) },
+        }
+    }
+
+    /// Attempts to parse a nested node in the `tag(fields)(children)` shape.
+    ///
+    /// A single paren group is only treated as a node if it contains at least one field or
+    /// belongs to a void element such as `br()`, otherwise calls such as `count()` would be
+    /// mistaken for elements.
+    fn parse_node(input: ParseStream) -> syn::Result<Option<Node>> {
+        let fork = input.fork();
+        let Ok(tag) = fork.parse::<Name>() else {
+            return Ok(None);
+        };
+        if !fork.peek(token::Paren) {
+            return Ok(None);
+        }
+
+        // `tag(..)(..)` and `"tag"(..)` are never valid expressions, so errors are reported
+        // rather than hidden
+        if fork.peek2(token::Paren) || input.peek(LitStr) {
+            return input.parse().map(Some);
+        }
+
+        if Child::peek_fields(&fork, Node::is_void(&tag)).unwrap_or(false) {
+            return input.parse().map(Some);
+        }
+
+        Ok(None)
+    }
+
+    fn peek_fields(fork: ParseStream, is_void: bool) -> syn::Result<bool> {
+        let content;
+        parenthesized!(content in fork);
+        let fields = content.parse_terminated(Field::parse, Token![,])?;
+
+        Ok(
+            (is_void || !fields.is_empty() && !Field::is_ambiguous(&fields))
+                && (fork.is_empty() || fork.peek(Token![,]) || fork.peek(Token![.])),
+        )
+    }
 }
 
 impl Parse for Child {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Child {
-            expr: input.parse()?,
-        })
+        if input.peek(Token![if]) {
+            return Ok(Child::If(input.parse()?));
+        }
+        if input.peek(Token![for]) {
+            return Ok(Child::For(Box::new(input.parse()?)));
+        }
+        if input.peek(Token![match]) {
+            return Ok(Child::Match(input.parse()?));
+        }
+        if input.peek(keyword::fragment) && input.peek2(token::Paren) {
+            return Ok(Child::Fragment(input.parse()?));
+        }
+        if input.peek(token::Paren) {
+            // Parenthesized expressions such as `(a + b).to_string()` are left alone
+            let fork = input.fork();
+            if let Ok(fragment) = fork.parse() {
+                if fork.is_empty() || fork.peek(Token![,]) {
+                    input.advance_to(&fork);
+                    return Ok(Child::Fragment(fragment));
+                }
+            }
+        }
+
+        match Child::parse_node(input)? {
+            Some(node) => Ok(Child::Node(Box::new(node))),
+            None => Ok(Child::Expr(input.parse()?)),
+        }
     }
 }
 
 impl ToTokens for Child {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let Self { expr } = self;
+        match self {
+            Child::Node(node) => tokens.extend(quote! {
+                .child(#node)
+            }),
+            Child::If(if_) => tokens.extend(quote! {
+                .child(#if_)
+            }),
+            Child::For(for_) => tokens.extend(quote! {
+                .child(#for_)
+            }),
+            Child::Match(match_) => tokens.extend(quote! {
+                .child(#match_)
+            }),
+            Child::Fragment(fragment) => tokens.extend(quote! {
+                .child(#fragment)
+            }),
+            Child::Expr(expr) => tokens.extend(quote! {
+                .child(#expr)
+            }),
+        }
+    }
+}
+
+/// Siblings without a wrapping element, written as `(a, b)` or `fragment(a, b)`.
+#[derive(Clone, Debug)]
+pub struct Fragment {
+    #[allow(dead_code)]
+    pub fragment_token: Option<keyword::fragment>,
+    #[allow(dead_code)]
+    pub paren_token: token::Paren,
+    pub children: Children,
+}
+
+impl Fragment {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(token::Paren) || input.peek(keyword::fragment) && input.peek2(token::Paren)
+    }
+}
+
+impl Parse for Fragment {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fragment_token = input.parse()?;
+        let content;
+        let paren_token = parenthesized!(content in input);
+
+        Ok(Fragment {
+            fragment_token,
+            paren_token,
+            children: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Fragment {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(self.children.to_fragment_tokens());
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct If {
+    #[allow(dead_code)]
+    pub if_token: Token![if],
+    pub cond: Expr,
+    #[allow(dead_code)]
+    pub brace_token: token::Brace,
+    pub then_branch: Children,
+    pub else_branch: Option<(Token![else], Box<Else>)>,
+}
+
+impl Parse for If {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let if_token = input.parse()?;
+        let cond = Expr::parse_without_eager_brace(input)?;
+        let content;
+        let brace_token = braced!(content in input);
+        let then_branch = content.parse()?;
+
+        let else_branch = if input.peek(Token![else]) {
+            Some((input.parse()?, Box::new(input.parse()?)))
+        } else {
+            None
+        };
+
+        Ok(If {
+            if_token,
+            cond,
+            brace_token,
+            then_branch,
+            else_branch,
+        })
+    }
+}
+
+impl If {
+    fn set_namespace(&mut self, namespace: Namespace) -> syn::Result<()> {
+        self.then_branch.set_namespace(namespace)?;
+        match self.else_branch.as_mut().map(|(_, else_)| &mut **else_) {
+            Some(Else::If(if_)) => if_.set_namespace(namespace),
+            Some(Else::Block(_, children)) => children.set_namespace(namespace),
+            None => Ok(()),
+        }
+    }
+
+    /// Expands the chain without the surrounding closure, so `else if` can nest.
+    fn to_branch_tokens(&self) -> proc_macro2::TokenStream {
+        let Self {
+            cond,
+            then_branch,
+            else_branch,
+            ..
+        } = self;
+
+        let then_branch = then_branch.to_view_tokens();
+        let else_branch = match else_branch.as_ref().map(|(_, else_)| &**else_) {
+            Some(Else::If(if_)) => if_.to_branch_tokens(),
+            Some(Else::Block(_, children)) => {
+                let children = children.to_view_tokens();
+                quote! { { #children } }
+            }
+            // Both branches need the same type, so a missing else renders nothing
+            None => quote! { { leptos::IntoView::into_view(()) } },
+        };
+
+        quote! {
+            if #cond { #then_branch } else #else_branch
+        }
+    }
+}
+
+impl ToTokens for If {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let branches = self.to_branch_tokens();
+
         tokens.extend(quote! {
-           .child(#expr)
-        });
+            move || #branches
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Else {
+    If(Box<If>),
+    Block(#[allow(dead_code)] token::Brace, Children),
+}
+
+impl Parse for Else {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![if]) {
+            Ok(Else::If(Box::new(input.parse()?)))
+        } else {
+            let content;
+            let brace_token = braced!(content in input);
+            Ok(Else::Block(brace_token, content.parse()?))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct For {
+    #[allow(dead_code)]
+    pub for_token: Token![for],
+    pub pat: Pat,
+    #[allow(dead_code)]
+    pub in_token: Token![in],
+    pub each: Expr,
+    #[allow(dead_code)]
+    pub key_token: keyword::key,
+    #[allow(dead_code)]
+    pub equals_token: Token![=],
+    pub key: Expr,
+    #[allow(dead_code)]
+    pub brace_token: token::Brace,
+    pub children: Children,
+}
+
+impl Parse for For {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let for_token = input.parse()?;
+        let pat = Pat::parse_single(input)?;
+        let in_token = input.parse()?;
+        let each = Expr::parse_without_eager_brace(input)?;
+        let key_token = input.parse().map_err(|_| {
+            input.error("expected `key = ...` after the iterator, `for` children are keyed")
+        })?;
+        let equals_token = input.parse()?;
+        let key = Expr::parse_without_eager_brace(input)?;
+        let content;
+        let brace_token = braced!(content in input);
+
+        Ok(For {
+            for_token,
+            pat,
+            in_token,
+            each,
+            key_token,
+            equals_token,
+            key,
+            brace_token,
+            children: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for For {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            pat,
+            each,
+            key,
+            children,
+            ..
+        } = self;
+
+        let children = children.to_view_tokens();
+
+        tokens.extend(quote! {
+            leptos::component_view(
+                &leptos::For,
+                leptos::component_props_builder(&leptos::For)
+                    .each(move || #each)
+                    .key(|#pat| #key)
+                    .view(move |#pat| #children)
+                    .build()
+            )
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Match {
+    #[allow(dead_code)]
+    pub match_token: Token![match],
+    pub expr: Expr,
+    #[allow(dead_code)]
+    pub brace_token: token::Brace,
+    pub arms: Vec<Arm>,
+}
+
+impl Parse for Match {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let match_token = input.parse()?;
+        let expr = Expr::parse_without_eager_brace(input)?;
+        let content;
+        let brace_token = braced!(content in input);
+
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            arms.push(content.parse()?);
+        }
+
+        Ok(Match {
+            match_token,
+            expr,
+            brace_token,
+            arms,
+        })
+    }
+}
+
+impl ToTokens for Match {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { expr, arms, .. } = self;
+
+        tokens.extend(quote! {
+            move || match #expr {
+                #(#arms)*
+            }
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Arm {
+    pub pat: Pat,
+    pub guard: Option<(Token![if], Expr)>,
+    #[allow(dead_code)]
+    pub fat_arrow_token: Token![=>],
+    #[allow(dead_code)]
+    pub brace_token: Option<token::Brace>,
+    pub body: Children,
+    #[allow(dead_code)]
+    pub comma: Option<Token![,]>,
+}
+
+impl Parse for Arm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pat = Pat::parse_multi_with_leading_vert(input)?;
+        let guard = if input.peek(Token![if]) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+        let fat_arrow_token = input.parse()?;
+
+        let (brace_token, body) = if input.peek(token::Brace) {
+            let content;
+            let brace_token = braced!(content in input);
+            (Some(brace_token), content.parse()?)
+        } else {
+            let mut body = Punctuated::new();
+            body.push(input.parse()?);
+            (None, Children(body))
+        };
+
+        // A comma is only optional after a braced body, as with rust match arms
+        let comma = if brace_token.is_some() || input.is_empty() {
+            input.parse()?
+        } else {
+            Some(input.parse()?)
+        };
+
+        Ok(Arm {
+            pat,
+            guard,
+            fat_arrow_token,
+            brace_token,
+            body,
+            comma,
+        })
+    }
+}
+
+impl ToTokens for Arm {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self {
+            pat, guard, body, ..
+        } = self;
+
+        let guard = guard
+            .as_ref()
+            .map(|(if_token, cond)| quote! { #if_token #cond });
+        let body = body.to_view_tokens();
+
+        tokens.extend(quote! {
+            #pat #guard => #body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(input: &str) -> String {
+        syn::parse_str::<Root>(input)
+            .unwrap()
+            .into_token_stream()
+            .to_string()
+    }
+
+    fn parse_error(input: &str) -> String {
+        syn::parse_str::<Root>(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn calls_without_fields_stay_expressions() {
+        assert_eq!(
+            expand("h1()(title(), label())"),
+            "leptos :: html :: h1 () . child (title ()) . child (label ())",
+        );
+    }
+
+    #[test]
+    fn spaced_hyphens_are_subtraction() {
+        assert_eq!(
+            expand("p()(total - count(), max - min(a, b))"),
+            "leptos :: html :: p () . child (total - count ()) . child (max - min (a , b))",
+        );
+    }
+
+    #[test]
+    fn nodes_in_children() {
+        assert_eq!(
+            expand(r#"div()(span()("x"), a(href = "/"), sl-badge()("y"))"#),
+            "leptos :: html :: div () \
+                . child (leptos :: html :: span () . child (\"x\")) \
+                . child (leptos :: html :: a () . attr (\"href\" , \"/\")) \
+                . child (leptos :: html :: custom (leptos :: html :: Custom :: new (\"sl-badge\")) \
+                . child (\"y\"))",
+        );
+    }
+
+    #[test]
+    fn void_elements_reject_children() {
+        let message = "`img` is a void element and cannot have children";
+        assert_eq!(parse_error(r#"img()("x")"#), message);
+        assert_eq!(parse_error(r#"img("x")"#), message);
+        assert_eq!(parse_error(r#"div()(img(src)("x"))"#), message);
+    }
+
+    #[test]
+    fn void_elements_without_children() {
+        assert!(syn::parse_str::<Root>("br()").is_ok());
+        assert!(syn::parse_str::<Root>(r#"div()(br(), img(src, alt = "x"))"#).is_ok());
+    }
+
+    #[test]
+    fn unknown_event() {
+        assert_eq!(
+            parse_error(r#"button(on:clik = |_| {})("x")"#),
+            "unknown event `clik`, did you mean `click`?",
+        );
+        assert_eq!(
+            parse_error(r#"button(on:zzzzz = |_| {})("x")"#),
+            "unknown event `zzzzz`, see `leptos::ev` for the supported events",
+        );
+    }
+
+    #[test]
+    fn id_values() {
+        assert_eq!(
+            expand(r#"div(id = "main")"#),
+            r#"leptos :: html :: div () . id ("main")"#,
+        );
+        assert_eq!(
+            expand("div(id = signal)"),
+            r#"leptos :: html :: div () . attr ("id" , signal)"#,
+        );
+        assert_eq!(
+            expand("div(id = move || name.get())"),
+            r#"leptos :: html :: div () . attr ("id" , move | | name . get ())"#,
+        );
+    }
+
+    #[test]
+    fn duplicate_id() {
+        assert_eq!(
+            parse_error(r#"div(id = "a", id = "b")("x")"#),
+            "duplicate `id` field, a node can only have one id",
+        );
+    }
+
+    #[test]
+    fn keyword_attribute_names() {
+        assert_eq!(
+            expand(r#"input(type = "text")"#),
+            r#"leptos :: html :: input () . attr ("type" , "text")"#,
+        );
+        assert_eq!(
+            expand(r#"label(for = "name")("Name")"#),
+            r#"leptos :: html :: label () . attr ("for" , "name") . child ("Name")"#,
+        );
+        assert_eq!(
+            parse_error("input(type)()"),
+            "`type` is a keyword, so it needs a value such as `type = ...`",
+        );
+    }
+
+    #[test]
+    fn static_style_before_properties() {
+        assert_eq!(
+            expand(r#"div(style:color = color, style = "width: 1px")"#),
+            r#"leptos :: html :: div () . attr ("style" , "width: 1px") . style ("color" , color)"#,
+        );
+    }
+
+    #[test]
+    fn class_names_with_decimals_and_fractions() {
+        assert_eq!(
+            expand("div(class:p-2.5 = a, class:w-1/2 = b)"),
+            r#"leptos :: html :: div () . class ("p-2.5" , a) . class ("w-1/2" , b)"#,
+        );
+    }
+
+    #[test]
+    fn lone_bare_names_are_children() {
+        for tag in ["h1", "li", "option", "p", "span"] {
+            for name in ["title", "value", "label", "id", "class", "style"] {
+                assert_eq!(
+                    expand(&format!("{tag}({name})")),
+                    format!("leptos :: html :: {tag} () . child ({name})"),
+                );
+            }
+        }
+        assert_eq!(
+            expand("svg()(circle(cx))"),
+            "leptos :: html :: svg () . child (circle (cx))",
+        );
+    }
+
+    #[test]
+    fn bare_names_are_punned_before_children() {
+        assert_eq!(
+            expand(r#"a(href, id, class)("home")"#),
+            r#"leptos :: html :: a () . attr ("href" , href) . attr ("id" , id) . classes (class) . child ("home")"#,
+        );
+        assert_eq!(
+            expand("svg()(circle(cx)())"),
+            r#"leptos :: html :: svg () . child (leptos :: svg :: circle () . attr ("cx" , cx))"#,
+        );
+    }
+
+    #[test]
+    fn bare_names_are_punned_with_a_value() {
+        assert_eq!(
+            expand(r#"a(href, target = "_blank")"#),
+            r#"leptos :: html :: a () . attr ("href" , href) . attr ("target" , "_blank")"#,
+        );
+        assert_eq!(
+            expand(r#"p()(a(href, target = "_blank"))"#),
+            r#"leptos :: html :: p () . child (leptos :: html :: a () . attr ("href" , href) . attr ("target" , "_blank"))"#,
+        );
+    }
+
+    #[test]
+    fn bare_names_are_punned_on_void_elements() {
+        assert_eq!(
+            expand("img(src)"),
+            r#"leptos :: html :: img () . attr ("src" , src)"#,
+        );
+    }
+
+    #[test]
+    fn component_punning() {
+        let props = "leptos :: component_props_builder (& Counter) . initial (initial) . build ()";
+        assert_eq!(
+            expand("Counter(initial)()"),
+            format!("leptos :: component_view (& Counter , {props})"),
+        );
+        assert_eq!(
+            expand("div()(Counter(initial)())"),
+            format!(
+                "leptos :: html :: div () . child (leptos :: component_view (& Counter , {props}))"
+            ),
+        );
+        assert_eq!(
+            expand("div()(Counter(initial))"),
+            "leptos :: html :: div () . child (Counter (initial))",
+        );
+    }
+
+    #[test]
+    fn keyword_boolean_attributes() {
+        assert_eq!(
+            expand("audio(loop, controls)"),
+            r#"leptos :: html :: audio () . attr ("loop" , true) . attr ("controls" , true)"#,
+        );
+        assert_eq!(
+            expand(r#"head()(script(async, src = "app.js"))"#),
+            r#"leptos :: html :: head () . child (leptos :: html :: script () . attr ("async" , true) . attr ("src" , "app.js"))"#,
+        );
+    }
+
+    #[test]
+    fn svg_namespace() {
+        assert_eq!(
+            expand(r##"svg()(a(href = "#")("x"), r#use(href = "#icon"))"##),
+            "leptos :: html :: svg () \
+                . child (leptos :: svg :: a () . attr (\"href\" , \"#\") . child (\"x\")) \
+                . child (leptos :: svg :: use_ () . attr (\"href\" , \"#icon\"))",
+        );
+    }
+
+    #[test]
+    fn namespace_reaches_control_flow() {
+        assert_eq!(
+            expand("svg()(if big { circle(r = 2) } else { circle(r = 1) })"),
+            "leptos :: html :: svg () . child (move || if big { \
+                leptos :: IntoView :: into_view (leptos :: svg :: circle () . attr (\"r\" , 2)) \
+                } else { \
+                leptos :: IntoView :: into_view (leptos :: svg :: circle () . attr (\"r\" , 1)) \
+                })",
+        );
+    }
+
+    #[test]
+    fn foreign_object_returns_to_html() {
+        assert_eq!(
+            expand(r#"svg()(foreignObject()(a(href = "/")("x")))"#),
+            r#"leptos :: html :: svg () . child (leptos :: svg :: foreignObject () . child (leptos :: html :: a () . attr ("href" , "/") . child ("x")))"#,
+        );
+    }
+
+    #[test]
+    fn math_namespace() {
+        assert_eq!(
+            expand(r#"math()(mi()("x"), annotation-xml()("y"))"#),
+            r#"leptos :: html :: math () . child (leptos :: math :: mi () . child ("x")) . child (leptos :: math :: annotation_xml () . child ("y"))"#,
+        );
+    }
+
+    #[test]
+    fn inner_html_with_children() {
+        assert_eq!(
+            parse_error(r#"div(inner_html = "<b>x</b>")("x")"#),
+            "`inner_html` would overwrite the children of this node",
+        );
+        assert_eq!(
+            expand(r#"div(inner_html = "<b>x</b>")"#),
+            r#"leptos :: html :: div () . inner_html ("<b>x</b>")"#,
+        );
+    }
+
+    #[test]
+    fn elements_outside_their_namespace() {
+        assert_eq!(
+            parse_error("div()(circle(r = 1))"),
+            "`circle` is an svg element, place it inside `svg`",
+        );
+        assert_eq!(
+            parse_error(r#"svg()(div()("x"))"#),
+            "`div` is an html element, place it inside `foreignObject`",
+        );
+        assert_eq!(
+            parse_error(r#"mi()("x")"#),
+            "`mi` is a math element, place it inside `math`",
+        );
+    }
+
+    #[test]
+    fn unknown_elements() {
+        assert_eq!(
+            parse_error(r#"dvi()("x")"#),
+            "unknown element `dvi`, did you mean `div`?",
+        );
+        assert_eq!(
+            parse_error(r#"my_widget()("x")"#),
+            "unknown element `my_widget`, custom elements must contain a hyphen such as `my-widget`",
+        );
+        assert_eq!(
+            parse_error(r#"fancyButton()("x")"#),
+            "unknown element `fancyButton`, custom elements must contain a hyphen such as `fancy-button`",
+        );
+        assert_eq!(
+            parse_error(r#"zzzzz()("x")"#),
+            "unknown element `zzzzz`, custom elements must contain a hyphen such as `my-zzzzz`",
+        );
+    }
+
+    #[test]
+    fn unknown_attributes() {
+        assert_eq!(
+            parse_error(r#"a(hreff = "/")("x")"#),
+            "unknown attribute `hreff` on `a`, did you mean `href`?",
+        );
+        assert_eq!(
+            parse_error("div(colspan = 2)"),
+            "unknown attribute `colspan` on `div`, write it as a string literal such as `\"colspan\"` to set it anyway",
+        );
+        assert_eq!(
+            parse_error(r#"td(onn = "x")"#),
+            "unknown attribute `onn` on `td`, write it as a string literal such as `\"onn\"` to set it anyway",
+        );
+        assert_eq!(
+            parse_error(r#"ul()(for n in v key = n { li(valu = n)("x") })"#),
+            "unknown attribute `valu` on `li`, did you mean `value`?",
+        );
+    }
+
+    #[test]
+    fn allowed_attributes() {
+        let inputs = [
+            "td(colspan = 2)",
+            r#"div(data-id = 1, aria-label = "x", onclick = "f()", hidden)"#,
+            r#"div("colspan" = 2)"#,
+            r#"meta(property = "og:title", content = "x")"#,
+            "sl-badge(variant = 1)",
+            "svg()(circle(cx = 1, cy = 1, r = 1))",
+        ];
+        for input in inputs {
+            assert!(syn::parse_str::<Root>(input).is_ok(), "{input}");
+        }
     }
 }
@@ -0,0 +1,71 @@
+/// Finds the candidate closest to `name`, if it is close enough to likely be a typo.
+pub fn did_you_mean<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let max_distance = name.chars().count().div_ceil(3);
+
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Optimal string alignment distance, a Levenshtein distance where swapping two adjacent
+/// characters counts as a single edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transposition_is_one_edit() {
+        assert_eq!(edit_distance("dvi", "div"), 1);
+        assert_eq!(did_you_mean("dvi", &["div", "span"]), Some("div"));
+    }
+
+    #[test]
+    fn distance_is_limited_to_a_third_of_the_name() {
+        assert_eq!(edit_distance("button", "butxyz"), 3);
+        assert_eq!(did_you_mean("button", &["butxyz"]), None);
+        assert_eq!(did_you_mean("button", &["butxyn"]), Some("butxyn"));
+        assert_eq!(did_you_mean("a", &["b"]), Some("b"));
+    }
+
+    #[test]
+    fn closest_candidate_wins() {
+        assert_eq!(did_you_mean("hreff", &["header", "href"]), Some("href"));
+    }
+
+    #[test]
+    fn no_suggestion_for_unrelated_names() {
+        assert_eq!(did_you_mean("zzz", &["div", "span"]), None);
+        assert_eq!(did_you_mean("div", &[]), None);
+    }
+}